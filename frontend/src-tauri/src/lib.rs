@@ -1,75 +1,213 @@
-use std::process::{Child, Command};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Mutex;
-use tauri::{Manager, RunEvent};
+use std::time::{Duration, Instant};
+use tauri::{Emitter, Manager, RunEvent};
 
-struct BackendProcess(Mutex<Option<Child>>);
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+const MAX_CONSECUTIVE_HEALTH_FAILURES: u32 = 3;
+const MAX_RESTART_ATTEMPTS: u32 = 6;
+const MAX_BACKOFF_SECS: u64 = 30;
+const PORT_PICK_ATTEMPTS: u32 = 3;
 
-fn find_backend_binary(app_handle: &tauri::AppHandle) -> Option<std::path::PathBuf> {
-    let binary_name = if cfg!(target_os = "windows") {
-        "nexa-backend.exe"
-    } else {
-        "nexa-backend"
-    };
-    
-    let mut search_paths: Vec<std::path::PathBuf> = Vec::new();
-    
-    if let Ok(resource_dir) = app_handle.path().resource_dir() {
-        log::info!("Resource dir: {}", resource_dir.display());
-        search_paths.push(resource_dir.join("binaries").join("nexa-backend").join(binary_name));
-        search_paths.push(resource_dir.join("nexa-backend").join(binary_name));
-        search_paths.push(resource_dir.join(binary_name));
-    }
-    
-    if let Ok(exe_path) = std::env::current_exe() {
-        log::info!("Exe path: {}", exe_path.display());
-        if let Some(exe_dir) = exe_path.parent() {
-            search_paths.push(exe_dir.join("binaries").join("nexa-backend").join(binary_name));
-            search_paths.push(exe_dir.join("nexa-backend").join(binary_name));
-            search_paths.push(exe_dir.join(binary_name));
-            search_paths.push(exe_dir.join("resources").join("binaries").join("nexa-backend").join(binary_name));
-            search_paths.push(exe_dir.join("resources").join("nexa-backend").join(binary_name));
-            search_paths.push(exe_dir.join("_up_").join("resources").join("binaries").join("nexa-backend").join(binary_name));
-            if let Some(parent_dir) = exe_dir.parent() {
-                search_paths.push(parent_dir.join("resources").join("binaries").join("nexa-backend").join(binary_name));
-                search_paths.push(parent_dir.join("resources").join("nexa-backend").join(binary_name));
-                search_paths.push(parent_dir.join("binaries").join("nexa-backend").join(binary_name));
-            }
+#[derive(Clone, Copy, Debug)]
+enum BackendState {
+    Starting,
+    Ready,
+    Failed,
+}
+
+#[derive(Clone)]
+enum BackendLaunch {
+    Bundled(std::path::PathBuf),
+    Dev,
+}
+
+impl BackendLaunch {
+    fn spawn(&self, port: u16) -> Option<Child> {
+        match self {
+            BackendLaunch::Bundled(path) => start_backend_bundled(path, port),
+            BackendLaunch::Dev => start_backend_dev(port),
         }
     }
-    
-    if let Ok(app_data) = app_handle.path().app_local_data_dir() {
-        log::info!("App local data dir: {}", app_data.display());
-        search_paths.push(app_data.join("binaries").join("nexa-backend").join(binary_name));
-        search_paths.push(app_data.join("nexa-backend").join(binary_name));
-    }
-    
-    for path in &search_paths {
-        log::info!("Checking path: {} (exists: {})", path.display(), path.exists());
-        if path.exists() {
-            if let Some(parent) = path.parent() {
-                log::info!("Working dir will be: {}", parent.display());
-                if let Ok(entries) = std::fs::read_dir(parent) {
-                    for entry in entries.flatten() {
-                        log::info!("  - {}", entry.path().display());
-                    }
-                }
+}
+
+struct BackendProcess {
+    child: Mutex<Option<Child>>,
+    launch: Mutex<Option<BackendLaunch>>,
+    port: Mutex<u16>,
+    restart_count: Mutex<u32>,
+    state: Mutex<BackendState>,
+    /// Set by the app's exit handler before it stops the backend, so the
+    /// supervisor thread knows not to treat that shutdown as a crash and spawn
+    /// a replacement that would outlive the app.
+    shutting_down: AtomicBool,
+    /// Bumped every time a backend instance is (re)spawned. A readiness probe
+    /// captures the generation it was spawned for and abandons itself once a
+    /// newer generation supersedes it, so a probe for a since-restarted or
+    /// since-killed instance can't clobber state or emit stale events.
+    generation: AtomicU64,
+    /// Whether the current generation has ever passed a health/readiness
+    /// check. Used to give a slow-starting backend the full readiness window
+    /// before the supervisor starts counting failed health checks against it.
+    ever_ready: Mutex<bool>,
+    spawned_at: Mutex<Instant>,
+}
+
+impl BackendProcess {
+    fn new() -> Self {
+        Self {
+            child: Mutex::new(None),
+            launch: Mutex::new(None),
+            port: Mutex::new(0),
+            restart_count: Mutex::new(0),
+            state: Mutex::new(BackendState::Starting),
+            shutting_down: AtomicBool::new(false),
+            generation: AtomicU64::new(0),
+            ever_ready: Mutex::new(false),
+            spawned_at: Mutex::new(Instant::now()),
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+struct BackendStatusDto {
+    state: String,
+    restart_count: u32,
+}
+
+#[tauri::command]
+fn backend_status(state: tauri::State<BackendProcess>) -> BackendStatusDto {
+    BackendStatusDto {
+        state: format!("{:?}", *state.state.lock().unwrap()),
+        restart_count: *state.restart_count.lock().unwrap(),
+    }
+}
+
+#[tauri::command]
+fn get_backend_url(state: tauri::State<BackendProcess>) -> String {
+    format!("http://127.0.0.1:{}", *state.port.lock().unwrap())
+}
+
+/// Binds an ephemeral port via the OS, reads back the assigned port, then drops
+/// the listener so the backend process can bind it itself.
+fn pick_free_port() -> std::io::Result<u16> {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let port = listener.local_addr()?.port();
+    drop(listener);
+    Ok(port)
+}
+
+/// Picks a free port and spawns the backend on it, retrying with a fresh port
+/// if picking the port or spawning the process fails. A port claimed by
+/// another process between the probe and the spawn does not make the spawn
+/// fail, so it is not retried here; the supervisor picks a new port when it
+/// restarts a backend that has exited.
+fn start_backend_with_port(launch: &BackendLaunch) -> Option<(Child, u16)> {
+    for attempt in 1..=PORT_PICK_ATTEMPTS {
+        let port = match pick_free_port() {
+            Ok(port) => port,
+            Err(e) => {
+                log::error!("Failed to pick a free port: {e}");
+                continue;
             }
-            return Some(path.clone());
+        };
+        if let Some(child) = launch.spawn(port) {
+            return Some((child, port));
         }
+        log::warn!(
+            "Backend failed to start on port {port} (attempt {attempt}/{PORT_PICK_ATTEMPTS})"
+        );
     }
-    
-    log::warn!("Backend binary not found in any search path");
-    if let Ok(resource_dir) = app_handle.path().resource_dir() {
+    None
+}
+
+fn backend_binary_name() -> &'static str {
+    if cfg!(target_os = "windows") {
+        "nexa-backend.exe"
+    } else {
+        "nexa-backend"
+    }
+}
+
+/// Locates the backend binary as shipped by the bundler, at its single known
+/// resource-relative path. The binary is normally not run from here directly —
+/// see `install_backend_binary`, which copies it into a writable,
+/// version-pinned location before launch. If that install fails, `run()`
+/// launches the binary from this resource path instead.
+fn find_bundled_binary(app_handle: &tauri::AppHandle) -> Option<std::path::PathBuf> {
+    let resource_dir = app_handle.path().resource_dir().ok()?;
+    let path = resource_dir
+        .join("binaries")
+        .join("nexa-backend")
+        .join(backend_binary_name());
+
+    if path.exists() {
+        Some(path)
+    } else {
+        log::warn!("Bundled backend binary not found at {}", path.display());
         log::warn!("Listing resource dir contents:");
         list_dir_recursive(&resource_dir, 0);
+        None
     }
-    if let Ok(exe_path) = std::env::current_exe() {
-        if let Some(exe_dir) = exe_path.parent() {
-            log::warn!("Listing exe dir contents:");
-            list_dir_recursive(exe_dir, 0);
-        }
+}
+
+fn sha256_file(path: &std::path::Path) -> std::io::Result<String> {
+    use sha2::{Digest, Sha256};
+
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Copies the bundled backend binary into a stable per-user location resolved
+/// via `dirs::data_local_dir()`, so it can be executed from a writable path
+/// instead of the (possibly read-only or sandboxed) resource directory. A
+/// SHA-256 of the shipped binary is recorded in a sidecar file; on later
+/// launches the binary is only re-copied if that checksum has changed or the
+/// installed copy no longer hashes to it.
+fn install_backend_binary(
+    app_handle: &tauri::AppHandle,
+    bundled_path: &std::path::Path,
+) -> Option<std::path::PathBuf> {
+    let install_dir = dirs::data_local_dir()?
+        .join(&app_handle.config().identifier)
+        .join("bin");
+    std::fs::create_dir_all(&install_dir).ok()?;
+
+    let binary_name = backend_binary_name();
+    let installed_path = install_dir.join(binary_name);
+    let checksum_path = install_dir.join(format!("{binary_name}.sha256"));
+
+    let bundled_checksum = sha256_file(bundled_path).ok()?;
+    let sidecar_matches_bundle = std::fs::read_to_string(&checksum_path)
+        .map(|stored| stored.trim() == bundled_checksum)
+        .unwrap_or(false);
+    // Re-hash the installed copy itself, not just the recorded checksum, so a
+    // corrupted or tampered installed binary is detected and re-extracted.
+    let installed_matches_bundle = sha256_file(&installed_path)
+        .map(|installed_checksum| installed_checksum == bundled_checksum)
+        .unwrap_or(false);
+
+    if sidecar_matches_bundle && installed_matches_bundle {
+        log::info!("Installed backend binary is up to date at {}", installed_path.display());
+        return Some(installed_path);
     }
-    None
+
+    log::info!("Installing backend binary to {}", installed_path.display());
+    std::fs::copy(bundled_path, &installed_path).ok()?;
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&installed_path).ok()?.permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&installed_path, perms).ok()?;
+    }
+
+    std::fs::write(&checksum_path, &bundled_checksum).ok()?;
+    Some(installed_path)
 }
 
 fn list_dir_recursive(dir: &std::path::Path, depth: usize) {
@@ -88,25 +226,28 @@ fn list_dir_recursive(dir: &std::path::Path, depth: usize) {
     }
 }
 
-fn start_backend_bundled(binary_path: &std::path::Path) -> Option<Child> {
+fn start_backend_bundled(binary_path: &std::path::Path, port: u16) -> Option<Child> {
     let working_dir = binary_path.parent()?;
     log::info!("Starting bundled backend from {}", binary_path.display());
     log::info!("Working directory: {}", working_dir.display());
-    
+
     #[cfg(target_os = "windows")]
     {
         use std::os::windows::process::CommandExt;
         const CREATE_NO_WINDOW: u32 = 0x08000000;
-        
+
         match Command::new(binary_path)
             .current_dir(working_dir)
-            .env("PORT", "8000")
+            .env("PORT", port.to_string())
             .env("HOST", "127.0.0.1")
             .creation_flags(CREATE_NO_WINDOW)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
             .spawn()
         {
-            Ok(child) => {
+            Ok(mut child) => {
                 log::info!("Backend spawned with PID: {}", child.id());
+                pipe_backend_output(&mut child);
                 return Some(child);
             }
             Err(e) => {
@@ -115,17 +256,20 @@ fn start_backend_bundled(binary_path: &std::path::Path) -> Option<Child> {
             }
         }
     }
-    
+
     #[cfg(not(target_os = "windows"))]
     {
         match Command::new(binary_path)
             .current_dir(working_dir)
-            .env("PORT", "8000")
+            .env("PORT", port.to_string())
             .env("HOST", "127.0.0.1")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
             .spawn()
         {
-            Ok(child) => {
+            Ok(mut child) => {
                 log::info!("Backend spawned with PID: {}", child.id());
+                pipe_backend_output(&mut child);
                 Some(child)
             }
             Err(e) => {
@@ -136,7 +280,7 @@ fn start_backend_bundled(binary_path: &std::path::Path) -> Option<Child> {
     }
 }
 
-fn start_backend_dev() -> Option<Child> {
+fn start_backend_dev(port: u16) -> Option<Child> {
     let exe_path = std::env::current_exe().ok()?;
     let project_root = exe_path
         .parent()?
@@ -163,19 +307,267 @@ fn start_backend_dev() -> Option<Child> {
     }
 
     log::info!("Starting dev backend from {}", backend_dir.display());
-    Command::new(&venv_python)
-        .args(["-m", "uvicorn", "main:app", "--host", "127.0.0.1", "--port", "8000"])
+    let mut child = Command::new(&venv_python)
+        .args([
+            "-m",
+            "uvicorn",
+            "main:app",
+            "--host",
+            "127.0.0.1",
+            "--port",
+            &port.to_string(),
+        ])
         .current_dir(&backend_dir)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
         .spawn()
-        .ok()
+        .ok()?;
+    pipe_backend_output(&mut child);
+    Some(child)
+}
+
+/// Reads the backend's stdout/stderr line-by-line and re-emits each line through
+/// the `backend` log target, classifying the level by whether the line contains
+/// a common uvicorn/Python level name (`CRITICAL`/`ERROR`, `WARNING`) so
+/// startup failures show up in the unified app log.
+fn pipe_backend_output(child: &mut Child) {
+    if let Some(stdout) = child.stdout.take() {
+        spawn_output_reader(stdout);
+    }
+    if let Some(stderr) = child.stderr.take() {
+        spawn_output_reader(stderr);
+    }
+}
+
+fn spawn_output_reader<R: std::io::Read + Send + 'static>(reader: R) {
+    std::thread::spawn(move || {
+        let reader = std::io::BufReader::new(reader);
+        for line in std::io::BufRead::lines(reader).map_while(Result::ok) {
+            if line.contains("CRITICAL") || line.contains("ERROR") {
+                log::error!(target: "backend", "{line}");
+            } else if line.contains("WARNING") {
+                log::warn!(target: "backend", "{line}");
+            } else {
+                log::info!(target: "backend", "{line}");
+            }
+        }
+    });
+}
+
+const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(5);
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Waits up to `SHUTDOWN_GRACE_PERIOD` for `child` to exit on its own, polling
+/// `try_wait` every `SHUTDOWN_POLL_INTERVAL`. Returns `true` if it exited in time.
+fn wait_for_exit(child: &mut Child) -> bool {
+    let deadline = std::time::Instant::now() + SHUTDOWN_GRACE_PERIOD;
+    while std::time::Instant::now() < deadline {
+        if matches!(child.try_wait(), Ok(Some(_))) {
+            return true;
+        }
+        std::thread::sleep(SHUTDOWN_POLL_INTERVAL);
+    }
+    false
+}
+
+#[cfg(not(target_os = "windows"))]
+fn request_graceful_shutdown(child: &Child) {
+    use nix::sys::signal::{kill, Signal};
+    use nix::unistd::Pid;
+
+    if let Err(e) = kill(Pid::from_raw(child.id() as i32), Signal::SIGTERM) {
+        log::warn!("Failed to send SIGTERM to backend: {e}");
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn request_graceful_shutdown(child: &Child) {
+    // Ask the console subsystem to close the process gracefully (equivalent to a
+    // WM_CLOSE) before resorting to TerminateProcess via `kill()`.
+    let status = Command::new("taskkill")
+        .args(["/PID", &child.id().to_string()])
+        .status();
+    if let Err(e) = status {
+        log::warn!("Failed to request graceful shutdown on Windows: {e}");
+    }
 }
 
 fn stop_backend(process: &mut Option<Child>) {
     if let Some(child) = process {
         log::info!("Stopping backend process...");
-        let _ = child.kill();
-        let _ = child.wait();
+        request_graceful_shutdown(child);
+
+        if wait_for_exit(child) {
+            log::info!("Backend exited gracefully");
+        } else {
+            log::warn!("Backend did not exit within {SHUTDOWN_GRACE_PERIOD:?}, killing it");
+            let _ = child.kill();
+            let _ = child.wait();
+        }
     }
+    *process = None;
+}
+
+fn check_backend_health(port: u16) -> bool {
+    let url = format!("http://127.0.0.1:{port}/health");
+    reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(2))
+        .build()
+        .and_then(|client| client.get(&url).send())
+        .map(|resp| resp.status().is_success())
+        .unwrap_or(false)
+}
+
+const READINESS_TIMEOUT: Duration = Duration::from_secs(30);
+const READINESS_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Polls the backend's root endpoint until it accepts a connection (or
+/// `READINESS_TIMEOUT` elapses), then emits `backend-ready`/`backend-timeout` so
+/// the webview knows when it's safe to issue its first request.
+///
+/// `generation` pins this probe to the backend instance it was spawned for; if
+/// the supervisor restarts the backend (bumping `state.generation`) while this
+/// probe is still polling the old port, it quietly abandons itself instead of
+/// emitting stale events or state for an instance that's already gone.
+fn spawn_readiness_probe(app_handle: tauri::AppHandle, port: u16, generation: u64) {
+    std::thread::spawn(move || {
+        let state = app_handle.state::<BackendProcess>();
+        let url = format!("http://127.0.0.1:{port}/");
+        let client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(2))
+            .build()
+            .ok();
+        let deadline = Instant::now() + READINESS_TIMEOUT;
+
+        while Instant::now() < deadline {
+            if state.generation.load(Ordering::SeqCst) != generation {
+                return;
+            }
+            if let Some(client) = &client {
+                if client.get(&url).send().is_ok() {
+                    if state.generation.load(Ordering::SeqCst) == generation {
+                        *state.state.lock().unwrap() = BackendState::Ready;
+                        *state.ever_ready.lock().unwrap() = true;
+                        log::info!(target: "backend", "Backend is ready on port {port}");
+                        let _ = app_handle.emit("backend-ready", ());
+                    }
+                    return;
+                }
+            }
+            std::thread::sleep(READINESS_POLL_INTERVAL);
+        }
+
+        if state.generation.load(Ordering::SeqCst) == generation {
+            log::warn!(target: "backend", "Backend did not become ready within {READINESS_TIMEOUT:?}");
+            let _ = app_handle.emit("backend-timeout", ());
+        }
+    });
+}
+
+/// Watches the backend child process and the `/health` endpoint, restarting the
+/// backend with exponential backoff if it dies or stops responding.
+fn spawn_supervisor(app_handle: tauri::AppHandle) {
+    std::thread::spawn(move || {
+        let state = app_handle.state::<BackendProcess>();
+        let mut consecutive_failures = 0u32;
+
+        loop {
+            std::thread::sleep(HEALTH_CHECK_INTERVAL);
+
+            if state.shutting_down.load(Ordering::SeqCst) {
+                return;
+            }
+
+            let exited = {
+                let mut child_guard = state.child.lock().unwrap();
+                match child_guard.as_mut() {
+                    Some(child) => matches!(child.try_wait(), Ok(Some(_))),
+                    None => true,
+                }
+            };
+
+            let port = *state.port.lock().unwrap();
+
+            if !exited && check_backend_health(port) {
+                consecutive_failures = 0;
+                *state.state.lock().unwrap() = BackendState::Ready;
+                *state.ever_ready.lock().unwrap() = true;
+                continue;
+            }
+
+            // A backend that hasn't become ready yet is allowed its full
+            // readiness window before failed health checks count against it —
+            // otherwise a slow-starting backend gets killed and restarted
+            // before `spawn_readiness_probe` ever gets to declare it ready.
+            if !exited
+                && !*state.ever_ready.lock().unwrap()
+                && state.spawned_at.lock().unwrap().elapsed() < READINESS_TIMEOUT
+            {
+                continue;
+            }
+
+            consecutive_failures += 1;
+            log::warn!(
+                target: "backend",
+                "Backend health check failed ({consecutive_failures}/{MAX_CONSECUTIVE_HEALTH_FAILURES}), process exited: {exited}"
+            );
+
+            if !exited && consecutive_failures < MAX_CONSECUTIVE_HEALTH_FAILURES {
+                continue;
+            }
+
+            if state.shutting_down.load(Ordering::SeqCst) {
+                return;
+            }
+
+            *state.state.lock().unwrap() = BackendState::Starting;
+            stop_backend(&mut state.child.lock().unwrap());
+
+            let mut restart_count = state.restart_count.lock().unwrap();
+            if *restart_count >= MAX_RESTART_ATTEMPTS {
+                *state.state.lock().unwrap() = BackendState::Failed;
+                log::error!(
+                    target: "backend",
+                    "Backend exceeded {MAX_RESTART_ATTEMPTS} restart attempts, giving up"
+                );
+                let _ = app_handle.emit("backend-failed", ());
+                return;
+            }
+
+            let backoff = Duration::from_secs((1u64 << (*restart_count).min(5)).min(MAX_BACKOFF_SECS));
+            *restart_count += 1;
+            let attempt = *restart_count;
+            drop(restart_count);
+
+            log::warn!(target: "backend", "Restarting backend (attempt {attempt}) in {backoff:?}");
+            std::thread::sleep(backoff);
+
+            // The backoff sleep can run for up to MAX_BACKOFF_SECS; re-check here
+            // too so a shutdown that arrives mid-sleep doesn't spawn a brand-new
+            // backend after the app has already torn down.
+            if state.shutting_down.load(Ordering::SeqCst) {
+                return;
+            }
+
+            let launch = state.launch.lock().unwrap().clone();
+            let restarted = launch.as_ref().and_then(start_backend_with_port);
+            match restarted {
+                Some((new_child, new_port)) => {
+                    log::info!(target: "backend", "Backend restarted successfully on port {new_port}");
+                    *state.port.lock().unwrap() = new_port;
+                    *state.child.lock().unwrap() = Some(new_child);
+                    *state.spawned_at.lock().unwrap() = Instant::now();
+                    *state.ever_ready.lock().unwrap() = false;
+                    let generation = state.generation.fetch_add(1, Ordering::SeqCst) + 1;
+                    spawn_readiness_probe(app_handle.clone(), new_port, generation);
+                }
+                None => {
+                    *state.child.lock().unwrap() = None;
+                }
+            }
+            consecutive_failures = 0;
+        }
+    });
 }
 
 #[allow(clippy::missing_panics_doc)]
@@ -184,7 +576,8 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_process::init())
-        .manage(BackendProcess(Mutex::new(None)))
+        .manage(BackendProcess::new())
+        .invoke_handler(tauri::generate_handler![backend_status, get_backend_url])
         .setup(|app| {
             app.handle().plugin(
                 tauri_plugin_log::Builder::default()
@@ -192,21 +585,47 @@ pub fn run() {
                     .build(),
             )?;
 
-            let backend = if let Some(binary_path) = find_backend_binary(app.handle()) {
-                start_backend_bundled(&binary_path)
+            let launch = if let Some(bundled_path) = find_bundled_binary(app.handle()) {
+                match install_backend_binary(app.handle(), &bundled_path) {
+                    Some(installed_path) => BackendLaunch::Bundled(installed_path),
+                    None => {
+                        log::warn!("Failed to install backend binary, launching it in place");
+                        BackendLaunch::Bundled(bundled_path)
+                    }
+                }
             } else {
                 log::info!("No bundled backend found, trying dev mode...");
-                start_backend_dev()
+                BackendLaunch::Dev
             };
 
-            if backend.is_some() {
-                log::info!("Backend process started successfully");
+            let started = start_backend_with_port(&launch);
+
+            if let Some((_, port)) = &started {
+                log::info!("Backend process started successfully on port {port}");
             } else {
                 log::warn!("Failed to start backend - ensure it's running separately");
             }
 
             let state = app.state::<BackendProcess>();
-            *state.0.lock().unwrap() = backend;
+            let (child, port) = match started {
+                Some((child, port)) => (Some(child), port),
+                None => (None, 0),
+            };
+            let spawned = child.is_some();
+            *state.child.lock().unwrap() = child;
+            *state.port.lock().unwrap() = port;
+            *state.launch.lock().unwrap() = Some(launch);
+            *state.spawned_at.lock().unwrap() = std::time::Instant::now();
+            *state.ever_ready.lock().unwrap() = false;
+
+            if spawned {
+                let generation = state.generation.fetch_add(1, Ordering::SeqCst) + 1;
+                spawn_readiness_probe(app.handle().clone(), port, generation);
+            } else {
+                *state.state.lock().unwrap() = BackendState::Failed;
+            }
+
+            spawn_supervisor(app.handle().clone());
 
             Ok(())
         })
@@ -215,7 +634,8 @@ pub fn run() {
         .run(|app_handle, event| {
             if let RunEvent::Exit = event {
                 let state = app_handle.state::<BackendProcess>();
-                stop_backend(&mut state.0.lock().unwrap());
+                state.shutting_down.store(true, Ordering::SeqCst);
+                stop_backend(&mut state.child.lock().unwrap());
             }
         });
 }